@@ -0,0 +1,145 @@
+use serde::Deserialize;
+use std::collections::HashSet;
+
+/// A named set of heuristics (Rails, Phoenix, Django, ...) describing how `unused`
+/// should analyze a project, along with the criteria used to decide whether it applies.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProjectConfiguration {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub match_criteria: MatchCriteria,
+}
+
+/// Criteria used to score how well a `ProjectConfiguration` fits an observed project.
+/// A configuration scores a point for every glob that matches a defined path and every
+/// signature token that's actually defined somewhere in the project.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct MatchCriteria {
+    #[serde(default)]
+    pub file_globs: Vec<String>,
+    #[serde(default)]
+    pub signature_tokens: Vec<String>,
+}
+
+impl Default for ProjectConfiguration {
+    fn default() -> Self {
+        ProjectConfiguration {
+            name: String::from("default"),
+            match_criteria: MatchCriteria::default(),
+        }
+    }
+}
+
+impl ProjectConfiguration {
+    pub(crate) fn score(&self, defined_paths: &HashSet<String>, defined_tokens: &HashSet<String>) -> usize {
+        let path_matches = self
+            .match_criteria
+            .file_globs
+            .iter()
+            .filter(|glob| defined_paths.iter().any(|path| glob_match(glob, path)))
+            .count();
+
+        let token_matches = self
+            .match_criteria
+            .signature_tokens
+            .iter()
+            .filter(|token| defined_tokens.contains(token.as_str()))
+            .count();
+
+        path_matches + token_matches
+    }
+}
+
+fn glob_match(pattern: &str, candidate: &str) -> bool {
+    let parts: Vec<&str> = pattern.split('*').collect();
+    if parts.len() == 1 {
+        return candidate == pattern;
+    }
+
+    let ends_with_wildcard = pattern.ends_with('*');
+    let last_index = parts.len() - 1;
+    let mut remainder = candidate;
+
+    for (index, part) in parts.iter().enumerate() {
+        if part.is_empty() {
+            continue;
+        }
+
+        match remainder.find(part) {
+            Some(position) => {
+                if index == 0 && position != 0 {
+                    return false;
+                }
+                let end = position + part.len();
+                if index == last_index && !ends_with_wildcard && end != remainder.len() {
+                    return false;
+                }
+                remainder = &remainder[end..];
+            }
+            None => return false,
+        }
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scores_a_point_per_matching_glob_and_signature_token() {
+        let config = ProjectConfiguration {
+            name: String::from("Rails"),
+            match_criteria: MatchCriteria {
+                file_globs: vec![String::from("app/models/*.rb"), String::from("config/routes.rb")],
+                signature_tokens: vec![String::from("ApplicationRecord")],
+            },
+        };
+
+        let defined_paths: HashSet<String> = vec![String::from("app/models/person.rb")]
+            .into_iter()
+            .collect();
+        let defined_tokens: HashSet<String> = vec![String::from("ApplicationRecord")]
+            .into_iter()
+            .collect();
+
+        assert_eq!(config.score(&defined_paths, &defined_tokens), 2);
+    }
+
+    #[test]
+    fn scores_zero_when_nothing_matches() {
+        let config = ProjectConfiguration {
+            name: String::from("Phoenix"),
+            match_criteria: MatchCriteria {
+                file_globs: vec![String::from("lib/*_web/router.ex")],
+                signature_tokens: vec![String::from("Plug.Router")],
+            },
+        };
+
+        let defined_paths: HashSet<String> = vec![String::from("app/models/person.rb")]
+            .into_iter()
+            .collect();
+        let defined_tokens = HashSet::new();
+
+        assert_eq!(config.score(&defined_paths, &defined_tokens), 0);
+    }
+
+    #[test]
+    fn does_not_score_a_path_whose_suffix_only_extends_past_the_glob() {
+        let config = ProjectConfiguration {
+            name: String::from("Rails"),
+            match_criteria: MatchCriteria {
+                file_globs: vec![String::from("app/models/*.rb")],
+                signature_tokens: vec![],
+            },
+        };
+
+        let defined_paths: HashSet<String> = vec![String::from("app/models/person.rb.bak")]
+            .into_iter()
+            .collect();
+
+        assert_eq!(config.score(&defined_paths, &HashSet::new()), 0);
+    }
+}
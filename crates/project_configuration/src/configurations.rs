@@ -0,0 +1,46 @@
+use crate::configuration::ProjectConfiguration;
+use serde::Deserialize;
+use std::collections::HashMap;
+use token_search::TokenSearchResults;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProjectConfigurations(HashMap<String, ProjectConfiguration>);
+
+impl ProjectConfigurations {
+    /// Parses `~/.unused.yml`-shaped YAML, where each top-level key is a configuration
+    /// name (`Rails`, `Phoenix`, ...) mapping to its match criteria and settings.
+    pub fn load(contents: &str) -> ProjectConfigurations {
+        let raw: HashMap<String, ProjectConfiguration> =
+            serde_yaml::from_str(contents).unwrap_or_default();
+
+        let named = raw
+            .into_iter()
+            .map(|(name, mut config)| {
+                config.name = name.clone();
+                (name, config)
+            })
+            .collect();
+
+        ProjectConfigurations(named)
+    }
+
+    pub fn get(&self, name: &str) -> Option<ProjectConfiguration> {
+        self.0.get(name).cloned()
+    }
+
+    /// Scores every loaded configuration against what was actually observed in
+    /// `results` (defined paths and defined token names) and returns the highest
+    /// scorer. Returns `None` if nothing scores above zero, so callers can fall back
+    /// to `ProjectConfiguration::default()`.
+    pub fn best_match(&self, results: &TokenSearchResults) -> Option<ProjectConfiguration> {
+        let defined_paths = results.defined_paths();
+        let defined_tokens = results.defined_token_names();
+
+        self.0
+            .values()
+            .map(|config| (config, config.score(&defined_paths, &defined_tokens)))
+            .filter(|(_, score)| *score > 0)
+            .max_by_key(|(_, score)| *score)
+            .map(|(config, _)| config.clone())
+    }
+}
@@ -0,0 +1,5 @@
+mod configuration;
+mod configurations;
+
+pub use configuration::{MatchCriteria, ProjectConfiguration};
+pub use configurations::ProjectConfigurations;
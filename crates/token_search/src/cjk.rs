@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+
+/// A word -> frequency prefix dictionary used for CJK max-probability segmentation.
+#[derive(Debug, Clone, Default)]
+pub struct Dictionary {
+    frequencies: HashMap<String, u64>,
+    total: u64,
+}
+
+impl Dictionary {
+    /// Loads a dictionary from `word<whitespace>frequency` lines. Blank lines and lines
+    /// starting with `#` are ignored.
+    pub fn load(contents: &str) -> Dictionary {
+        let mut frequencies = HashMap::new();
+        let mut total = 0;
+
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut parts = line.split_whitespace();
+            if let (Some(word), Some(freq)) = (parts.next(), parts.next()) {
+                if let Ok(freq) = freq.parse::<u64>() {
+                    total += freq;
+                    frequencies.insert(word.to_string(), freq);
+                }
+            }
+        }
+
+        Dictionary { frequencies, total }
+    }
+
+    fn contains(&self, word: &str) -> bool {
+        self.frequencies.contains_key(word)
+    }
+
+    fn log_probability(&self, word: &str) -> f64 {
+        let freq = *self.frequencies.get(word).unwrap_or(&1);
+        let total = self.total.max(1) as f64;
+        (freq as f64 / total).ln()
+    }
+}
+
+/// Segments `run` (a run of non-ASCII word characters, e.g. CJK) into the
+/// highest-probability sequence of dictionary words.
+///
+/// Builds a DAG over the run where an edge `(i, j)` exists whenever `run[i..j]` is a
+/// dictionary word (or a single character, as a fallback), then runs a right-to-left
+/// DP computing `route[i] = max over j of (log(freq[i..j] / total) + route[j])` to
+/// recover the highest-probability split. Characters with no dictionary match end up
+/// as single-character tokens.
+pub fn segment(run: &str, dictionary: &Dictionary) -> Vec<String> {
+    let chars: Vec<char> = run.chars().collect();
+    let n = chars.len();
+    if n == 0 {
+        return vec![];
+    }
+
+    // route[i] = (best log-probability achievable from i to the end, the index the
+    // best first edge out of i jumps to)
+    let mut route = vec![(f64::NEG_INFINITY, n); n + 1];
+    route[n] = (0.0, n);
+
+    for i in (0..n).rev() {
+        for j in (i + 1)..=n {
+            let word: String = chars[i..j].iter().collect();
+            if j - i > 1 && !dictionary.contains(&word) {
+                continue;
+            }
+
+            let score = dictionary.log_probability(&word) + route[j].0;
+            if score > route[i].0 {
+                route[i] = (score, j);
+            }
+        }
+    }
+
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < n {
+        let j = route[i].1;
+        tokens.push(chars[i..j].iter().collect());
+        i = j;
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn segments_using_dictionary_words_over_single_characters() {
+        let dictionary = Dictionary::load("北京 1000\n大学 1000\n北京大学 1\n");
+
+        assert_eq!(segment("北京大学", &dictionary), vec!["北京", "大学"]);
+    }
+
+    #[test]
+    fn falls_back_to_single_characters_with_an_empty_dictionary() {
+        let dictionary = Dictionary::default();
+
+        assert_eq!(segment("日本語", &dictionary), vec!["日", "本", "語"]);
+    }
+}
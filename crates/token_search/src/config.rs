@@ -0,0 +1,46 @@
+use crate::cjk::Dictionary;
+use crate::language_restriction::LanguageRestriction;
+use crate::progress::{BarProgressReporter, ProgressReporter};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct TokenSearchConfig {
+    pub progress_reporter: Arc<dyn ProgressReporter + Send + Sync>,
+    pub language_restriction: LanguageRestriction,
+    pub tokenizer: TokenizerConfig,
+}
+
+impl Default for TokenSearchConfig {
+    fn default() -> Self {
+        TokenSearchConfig {
+            progress_reporter: Arc::new(BarProgressReporter::default()),
+            language_restriction: LanguageRestriction::default(),
+            tokenizer: TokenizerConfig::default(),
+        }
+    }
+}
+
+/// Controls how file contents are scanned into identifier tokens for occurrence counting.
+#[derive(Debug, Clone)]
+pub struct TokenizerConfig {
+    /// When true, each identifier run is additionally split on camelCase transitions
+    /// and `_`/`-` separators, so compound identifiers also match on their parts.
+    pub split_compound_identifiers: bool,
+    /// When true, runs of non-ASCII word characters (e.g. CJK identifiers or string
+    /// literals) are additionally segmented using `cjk_dictionary`; the whole run still
+    /// counts as one token as well.
+    pub segment_cjk: bool,
+    /// The word -> frequency dictionary used for CJK segmentation. Only consulted when
+    /// `segment_cjk` is set.
+    pub cjk_dictionary: Dictionary,
+}
+
+impl Default for TokenizerConfig {
+    fn default() -> Self {
+        TokenizerConfig {
+            split_compound_identifiers: false,
+            segment_cjk: false,
+            cjk_dictionary: Dictionary::default(),
+        }
+    }
+}
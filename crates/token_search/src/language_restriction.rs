@@ -0,0 +1,75 @@
+use read_ctags::Language;
+use std::collections::HashSet;
+use std::fmt;
+
+#[derive(Debug, Clone)]
+pub enum LanguageRestriction {
+    All,
+    Only(HashSet<Language>),
+    Except(HashSet<Language>),
+}
+
+impl Default for LanguageRestriction {
+    fn default() -> Self {
+        LanguageRestriction::All
+    }
+}
+
+impl LanguageRestriction {
+    /// Whether a token defined in `languages` should be kept under this restriction.
+    pub fn permits(&self, languages: &[Language]) -> bool {
+        match self {
+            LanguageRestriction::All => true,
+            LanguageRestriction::Only(allowed) => languages.iter().any(|l| allowed.contains(l)),
+            LanguageRestriction::Except(excluded) => languages.iter().any(|l| !excluded.contains(l)),
+        }
+    }
+}
+
+impl fmt::Display for LanguageRestriction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LanguageRestriction::All => write!(f, "all languages"),
+            LanguageRestriction::Only(languages) => {
+                write!(f, "only {}", format_languages(languages))
+            }
+            LanguageRestriction::Except(languages) => {
+                write!(f, "all except {}", format_languages(languages))
+            }
+        }
+    }
+}
+
+fn format_languages(languages: &HashSet<Language>) -> String {
+    let mut names: Vec<String> = languages.iter().map(|l| format!("{:?}", l)).collect();
+    names.sort();
+    names.join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_permits_any_language() {
+        assert!(LanguageRestriction::All.permits(&[Language::Ruby]));
+        assert!(LanguageRestriction::All.permits(&[]));
+    }
+
+    #[test]
+    fn only_permits_tokens_defined_in_one_of_the_allowed_languages() {
+        let restriction = LanguageRestriction::Only(vec![Language::Ruby].into_iter().collect());
+
+        assert!(restriction.permits(&[Language::Ruby]));
+        assert!(!restriction.permits(&[]));
+    }
+
+    #[test]
+    fn except_excludes_tokens_only_defined_in_the_excluded_languages() {
+        let excluding_ruby = LanguageRestriction::Except(vec![Language::Ruby].into_iter().collect());
+        let excluding_nothing = LanguageRestriction::Except(HashSet::new());
+
+        assert!(!excluding_ruby.permits(&[Language::Ruby]));
+        assert!(excluding_nothing.permits(&[Language::Ruby]));
+    }
+}
@@ -0,0 +1,18 @@
+mod cjk;
+mod config;
+mod language_restriction;
+mod progress;
+mod result;
+mod results;
+mod token;
+mod tokenizer;
+
+pub use cjk::Dictionary;
+pub use config::{TokenSearchConfig, TokenizerConfig};
+pub use language_restriction::LanguageRestriction;
+pub use progress::{
+    BarProgressReporter, JsonProgressReporter, NullProgressReporter, ProgressPhase, ProgressReporter,
+};
+pub use result::TokenSearchResult;
+pub use results::TokenSearchResults;
+pub use token::Token;
@@ -0,0 +1,29 @@
+use crate::token::Token;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+/// A single token paired with where it was defined (via `Token`) and, per file, how many
+/// times its whole identifier occurred across the project.
+#[derive(Clone, Serialize)]
+pub struct TokenSearchResult {
+    pub token: Token,
+    pub occurrences: HashMap<String, usize>,
+}
+
+impl TokenSearchResult {
+    pub fn defined_paths(&self) -> HashSet<String> {
+        self.token.defined_paths()
+    }
+
+    pub fn occurred_paths(&self) -> HashSet<String> {
+        self.occurrences
+            .iter()
+            .filter(|(_, count)| **count > 0)
+            .map(|(path, _)| path.to_string())
+            .collect()
+    }
+
+    pub fn total_occurrences(&self) -> usize {
+        self.occurrences.values().sum()
+    }
+}
@@ -0,0 +1,128 @@
+use crate::config::TokenSearchConfig;
+use crate::progress::ProgressPhase;
+use crate::result::TokenSearchResult;
+use crate::token::Token;
+use crate::tokenizer;
+use read_ctags::Language;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const IGNORED_DIRECTORIES: &[&str] = &[".git", "target", "node_modules", "vendor"];
+
+pub struct TokenSearchResults(Vec<TokenSearchResult>);
+
+impl TokenSearchResults {
+    pub fn generate_with_config(config: &TokenSearchConfig) -> Self {
+        let reporter = &config.progress_reporter;
+
+        reporter.start_phase(ProgressPhase::ParsingCtags, 1);
+        let tokens: Vec<Token> = Token::all()
+            .into_iter()
+            .filter(|token| config.language_restriction.permits(&token.languages()))
+            .collect();
+        reporter.finish_phase(ProgressPhase::ParsingCtags);
+
+        let file_tokens = Self::scan_project_files(config);
+
+        reporter.start_phase(ProgressPhase::AnalyzingTokens, tokens.len());
+        let results = tokens
+            .into_iter()
+            .enumerate()
+            .map(|(index, token)| {
+                let occurrences = Self::count_occurrences(&token, &file_tokens);
+                reporter.increment(ProgressPhase::AnalyzingTokens, index + 1);
+                TokenSearchResult { token, occurrences }
+            })
+            .collect();
+        reporter.finish_phase(ProgressPhase::AnalyzingTokens);
+
+        TokenSearchResults(results)
+    }
+
+    /// Every path a token in this set was defined in, across the whole project.
+    pub fn defined_paths(&self) -> HashSet<String> {
+        self.0.iter().flat_map(|result| result.defined_paths()).collect()
+    }
+
+    /// Every language a token in this set was defined in, across the whole project.
+    pub fn languages(&self) -> HashSet<Language> {
+        self.0
+            .iter()
+            .flat_map(|result| result.token.languages())
+            .collect()
+    }
+
+    /// The exact identifier name of every token in this set.
+    pub fn defined_token_names(&self) -> HashSet<String> {
+        self.0.iter().map(|result| result.token.token.clone()).collect()
+    }
+
+    fn count_occurrences(
+        token: &Token,
+        file_tokens: &HashMap<String, HashMap<String, usize>>,
+    ) -> HashMap<String, usize> {
+        file_tokens
+            .iter()
+            .filter_map(|(path, tokens)| {
+                tokens.get(&token.token).map(|&count| (path.to_string(), count))
+            })
+            .collect()
+    }
+
+    fn scan_project_files(config: &TokenSearchConfig) -> HashMap<String, HashMap<String, usize>> {
+        let reporter = &config.progress_reporter;
+        let paths = walk(Path::new("."));
+        let mut file_tokens = HashMap::new();
+
+        reporter.start_phase(ProgressPhase::ScanningFiles, paths.len());
+        for (index, path) in paths.iter().enumerate() {
+            if let Ok(contents) = fs::read_to_string(path) {
+                let tokens = tokenizer::scan(&contents, &config.tokenizer);
+                file_tokens.insert(path.display().to_string(), tokens);
+            }
+            reporter.increment(ProgressPhase::ScanningFiles, index + 1);
+        }
+        reporter.finish_phase(ProgressPhase::ScanningFiles);
+
+        file_tokens
+    }
+}
+
+impl IntoIterator for TokenSearchResults {
+    type Item = TokenSearchResult;
+    type IntoIter = std::vec::IntoIter<TokenSearchResult>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+fn walk(root: &Path) -> Vec<PathBuf> {
+    let mut paths = vec![];
+    let entries = match fs::read_dir(root) {
+        Ok(entries) => entries,
+        Err(_) => return paths,
+    };
+
+    for entry in entries.filter_map(Result::ok) {
+        let path = entry.path();
+        let is_ignored = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .map(|name| IGNORED_DIRECTORIES.contains(&name))
+            .unwrap_or(false);
+
+        if is_ignored {
+            continue;
+        }
+
+        if path.is_dir() {
+            paths.extend(walk(&path));
+        } else {
+            paths.push(path);
+        }
+    }
+
+    paths
+}
@@ -0,0 +1,142 @@
+use crate::cjk;
+use crate::config::TokenizerConfig;
+use std::collections::HashMap;
+
+/// Scans `contents` into the identifier tokens it contains, each mapped to how many
+/// times it occurred.
+///
+/// A token is a maximal run of word characters (`[A-Za-z0-9_]` plus any other
+/// alphanumeric unicode scalar, so CJK runs are captured too). Every run is counted
+/// as a whole token. When `config.segment_cjk` is set, runs containing non-ASCII
+/// characters are additionally segmented via `cjk::segment`, and each segment counts
+/// as a token too. When `config.split_compound_identifiers` is set, every other run is
+/// additionally sub-split on `_`/`-` separators and camelCase transitions, so e.g.
+/// `parse_ctags_output` also counts occurrences of `parse`, `ctags` and `output`, and
+/// `parseCtagsOutput` of `parse`, `Ctags` and `Output` (parts keep their case).
+pub fn scan(contents: &str, config: &TokenizerConfig) -> HashMap<String, usize> {
+    let mut tokens = HashMap::new();
+
+    for run in identifier_runs(contents) {
+        increment(&mut tokens, run.to_string());
+
+        if config.segment_cjk && !run.is_ascii() {
+            for piece in cjk::segment(run, &config.cjk_dictionary) {
+                increment(&mut tokens, piece);
+            }
+        } else if config.split_compound_identifiers {
+            for piece in split_compound(run) {
+                increment(&mut tokens, piece);
+            }
+        }
+    }
+
+    tokens
+}
+
+fn increment(tokens: &mut HashMap<String, usize>, token: String) {
+    *tokens.entry(token).or_insert(0) += 1;
+}
+
+fn identifier_runs(contents: &str) -> impl Iterator<Item = &str> {
+    contents
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .filter(|run| !run.is_empty())
+}
+
+fn split_compound(run: &str) -> Vec<String> {
+    run.split(|c| c == '_' || c == '-')
+        .filter(|part| !part.is_empty())
+        .flat_map(split_camel_case)
+        .collect()
+}
+
+fn split_camel_case(run: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+
+    for c in run.chars() {
+        if c.is_uppercase() && !current.is_empty() {
+            parts.push(current.clone());
+            current.clear();
+        }
+        current.push(c);
+    }
+
+    if !current.is_empty() {
+        parts.push(current);
+    }
+
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scan_finds_whole_identifiers_only() {
+        let config = TokenizerConfig::default();
+        let tokens = scan("let filename = surname(name);", &config);
+
+        assert!(tokens.contains_key("name"));
+        assert!(tokens.contains_key("filename"));
+        assert!(tokens.contains_key("surname"));
+        assert_eq!(tokens.len(), 4);
+    }
+
+    #[test]
+    fn scan_without_splitting_does_not_expose_substrings() {
+        let config = TokenizerConfig::default();
+        let tokens = scan("let filename = path;", &config);
+
+        assert!(tokens.contains_key("filename"));
+        assert!(!tokens.contains_key("name"));
+    }
+
+    #[test]
+    fn scan_counts_each_occurrence_of_a_repeated_identifier() {
+        let config = TokenizerConfig::default();
+        let tokens = scan("let name = greet(name, name);", &config);
+
+        assert_eq!(tokens.get("name"), Some(&3));
+    }
+
+    #[test]
+    fn scan_with_compound_splitting_exposes_snake_and_camel_parts() {
+        let mut config = TokenizerConfig::default();
+        config.split_compound_identifiers = true;
+
+        let tokens = scan("parse_ctags_output(parseCtagsOutput)", &config);
+
+        assert!(tokens.contains_key("parse_ctags_output"));
+        assert!(tokens.contains_key("parse"));
+        assert!(tokens.contains_key("ctags"));
+        assert!(tokens.contains_key("output"));
+        assert!(tokens.contains_key("parseCtagsOutput"));
+        assert!(tokens.contains_key("Ctags"));
+    }
+
+    #[test]
+    fn scan_with_segment_cjk_splits_non_ascii_runs_via_the_dictionary() {
+        use crate::cjk::Dictionary;
+
+        let mut config = TokenizerConfig::default();
+        config.segment_cjk = true;
+        config.cjk_dictionary = Dictionary::load("北京 1000\n大学 1000\n北京大学 1\n");
+
+        let tokens = scan("北京大学", &config);
+
+        assert!(tokens.contains_key("北京"));
+        assert!(tokens.contains_key("大学"));
+    }
+
+    #[test]
+    fn scan_without_segment_cjk_treats_non_ascii_runs_as_one_token() {
+        let config = TokenizerConfig::default();
+
+        let tokens = scan("北京大学", &config);
+
+        assert!(tokens.contains_key("北京大学"));
+        assert!(!tokens.contains_key("北京"));
+    }
+}
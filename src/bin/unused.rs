@@ -3,15 +3,22 @@ use dirs;
 use itertools::Itertools;
 use project_configuration::{ProjectConfiguration, ProjectConfigurations};
 use read_ctags::Language;
+use serde::Serialize;
 use serde_json;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
 use std::fs;
 use std::io;
 use std::iter::FromIterator;
 use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
 use structopt::StructOpt;
 use token_analysis::*;
-use token_search::{LanguageRestriction, TokenSearchConfig, TokenSearchResults};
+use token_search::{
+    BarProgressReporter, Dictionary, JsonProgressReporter, LanguageRestriction,
+    NullProgressReporter, ProgressReporter, TokenSearchConfig, TokenSearchResults,
+};
 
 #[derive(Debug, StructOpt)]
 #[structopt(
@@ -19,19 +26,59 @@ use token_search::{LanguageRestriction, TokenSearchConfig, TokenSearchResults};
     about = "A command line tool to identify potentially unused code",
     setting = structopt::clap::AppSettings::ColoredHelp
 )]
-struct Flags {
+enum Command {
+    /// List tokens identified as unused, sorted by usage likelihood
+    List(ListOpts),
+
+    /// Print aggregate metrics about the codebase's tokens by language and likelihood
+    Stats(StatsOpts),
+}
+
+#[derive(Debug, StructOpt)]
+struct SearchOpts {
     /// Disable color output
     #[structopt(long)]
     no_color: bool,
 
+    /// How search progress is reported: an interactive bar, newline-delimited JSON
+    /// events written to stderr (for editor integrations and CI), or nothing at all
+    #[structopt(long, possible_values = &ProgressMode::variants(), default_value = "bar", case_insensitive = true)]
+    progress: ProgressMode,
+
+    /// Limit tokens to those defined in the provided file extension(s)
+    #[structopt(long, possible_values = &Language::extensions(), use_delimiter = true)]
+    only_filetypes: Vec<Language>,
+
+    /// Limit tokens to those defined except for the provided file extension(s)
+    #[structopt(long, possible_values = &Language::extensions(), use_delimiter = true)]
+    except_filetypes: Vec<Language>,
+
+    /// Segment non-ASCII (e.g. CJK) identifier and string runs into dictionary words
+    /// instead of treating each run as a single opaque token
+    #[structopt(long)]
+    segment_cjk: bool,
+
+    /// Path to a `word<whitespace>frequency` dictionary file used for `--segment-cjk`
+    /// max-probability segmentation
+    #[structopt(long)]
+    cjk_dictionary: Option<String>,
+
+    /// Also sub-split each identifier run on camelCase transitions and `_`/`-`
+    /// separators, so e.g. `parse_ctags_output` additionally yields `parse`, `ctags`
+    /// and `output` as standalone tokens
+    #[structopt(long)]
+    split_compound_identifiers: bool,
+}
+
+#[derive(Debug, StructOpt)]
+struct ListOpts {
+    #[structopt(flatten)]
+    search: SearchOpts,
+
     /// Render output as JSON
     #[structopt(long)]
     json: bool,
 
-    /// Hide progress bar
-    #[structopt(long, short = "P")]
-    no_progress: bool,
-
     /// Include tokens that fall into any likelihood category
     #[structopt(long, short = "a")]
     all_likelihoods: bool,
@@ -49,23 +96,84 @@ struct Flags {
     /// Reverse sort order
     #[structopt(long)]
     reverse: bool,
+}
 
-    /// Limit tokens to those defined in the provided file extension(s)
-    #[structopt(long, possible_values = &Language::extensions(), use_delimiter = true)]
-    only_filetypes: Vec<Language>,
+#[derive(Debug, StructOpt)]
+struct StatsOpts {
+    #[structopt(flatten)]
+    search: SearchOpts,
 
-    /// Limit tokens to those defined except for the provided file extension(s)
-    #[structopt(long, possible_values = &Language::extensions(), use_delimiter = true)]
-    except_filetypes: Vec<Language>,
+    /// Render output as JSON, suitable for tracking dead-code trends over time in CI
+    #[structopt(long)]
+    json: bool,
+}
+
+#[derive(Serialize)]
+struct Stats {
+    total_tokens: usize,
+    distinct_defining_files: usize,
+    average_occurrences_per_token: f64,
+    tokens_per_language: HashMap<String, usize>,
+    tokens_per_likelihood: HashMap<String, usize>,
+}
+
+#[derive(Debug, Clone)]
+enum ProgressMode {
+    Bar,
+    Json,
+    Silent,
+}
+
+impl ProgressMode {
+    fn variants() -> Vec<&'static str> {
+        vec!["bar", "json", "silent"]
+    }
+
+    fn to_reporter(&self) -> Arc<dyn ProgressReporter + Send + Sync> {
+        match self {
+            ProgressMode::Bar => Arc::new(BarProgressReporter::default()),
+            ProgressMode::Json => Arc::new(JsonProgressReporter::default()),
+            ProgressMode::Silent => Arc::new(NullProgressReporter::default()),
+        }
+    }
+}
+
+impl FromStr for ProgressMode {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_lowercase().as_str() {
+            "bar" => Ok(ProgressMode::Bar),
+            "json" => Ok(ProgressMode::Json),
+            "silent" => Ok(ProgressMode::Silent),
+            other => Err(format!("unrecognized progress mode: {}", other)),
+        }
+    }
+}
+
+impl fmt::Display for ProgressMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ProgressMode::Bar => write!(f, "bar"),
+            ProgressMode::Json => write!(f, "json"),
+            ProgressMode::Silent => write!(f, "silent"),
+        }
+    }
 }
 
 fn main() {
-    let cmd = Flags::from_args();
+    match Command::from_args() {
+        Command::List(cmd) => run_list(cmd),
+        Command::Stats(cmd) => run_stats(cmd),
+    }
+}
 
-    if cmd.no_color {
+fn run_list(cmd: ListOpts) {
+    if cmd.search.no_color {
         control::set_override(false);
     }
-    let search_config = build_token_search_config(&cmd);
+
+    let search_config = build_token_search_config(&cmd.search);
     let analysis_filter = build_analysis_filter(&cmd);
 
     let results = TokenSearchResults::generate_with_config(&search_config);
@@ -143,12 +251,98 @@ fn main() {
     }
 }
 
-fn build_token_search_config(cmd: &Flags) -> TokenSearchConfig {
-    let mut search_config = TokenSearchConfig::default();
-    if cmd.no_progress {
-        search_config.display_progress = false;
+fn run_stats(cmd: StatsOpts) {
+    if cmd.search.no_color {
+        control::set_override(false);
     }
 
+    let search_config = build_token_search_config(&cmd.search);
+    let results = TokenSearchResults::generate_with_config(&search_config);
+    let config = calculate_config_by_results(&results).unwrap_or(ProjectConfiguration::default());
+
+    let outcome = TokenUsageResults::calculate(&search_config, results, &config);
+
+    let mut analysis_filter = AnalysisFilter::default();
+    analysis_filter.usage_likelihood_filter = vec![
+        UsageLikelihoodStatus::High,
+        UsageLikelihoodStatus::Medium,
+        UsageLikelihoodStatus::Low,
+    ];
+
+    let stats = calculate_stats(&outcome.filter(&analysis_filter));
+
+    if cmd.json {
+        println!("{}", serde_json::to_string(&stats).unwrap())
+    } else {
+        println!("{}", "== UNUSED STATS ==".white());
+        println!("   Total tokens: {}", colorize_total(stats.total_tokens));
+        println!(
+            "   Distinct defining files: {}",
+            colorize_total(stats.distinct_defining_files)
+        );
+        println!(
+            "   Average occurrences per token: {}",
+            format!("{:.2}", stats.average_occurrences_per_token).yellow()
+        );
+
+        println!("");
+        println!("   Tokens per language:");
+        for (language, count) in stats.tokens_per_language.iter().sorted() {
+            println!("   * {}: {}", language, count.to_string().yellow());
+        }
+
+        println!("");
+        println!("   Tokens per likelihood:");
+        for (likelihood, count) in stats.tokens_per_likelihood.iter().sorted() {
+            println!("   * {}: {}", likelihood, count.to_string().yellow());
+        }
+        println!("");
+    }
+}
+
+fn calculate_stats(analyses: &[Analysis]) -> Stats {
+    let total_tokens = analyses.len();
+
+    let mut tokens_per_language = HashMap::new();
+    let mut tokens_per_likelihood = HashMap::new();
+    let mut defining_files = HashSet::new();
+    let mut total_occurrences = 0;
+
+    for analysis in analyses {
+        let languages: HashSet<_> = analysis.result.token.languages().into_iter().collect();
+        for language in languages {
+            *tokens_per_language
+                .entry(format!("{:?}", language))
+                .or_insert(0) += 1;
+        }
+
+        *tokens_per_likelihood
+            .entry(analysis.usage_likelihood.status.to_string())
+            .or_insert(0) += 1;
+
+        defining_files.extend(analysis.result.defined_paths());
+        total_occurrences += analysis.result.total_occurrences();
+    }
+
+    let average_occurrences_per_token = if total_tokens == 0 {
+        0.0
+    } else {
+        total_occurrences as f64 / total_tokens as f64
+    };
+
+    Stats {
+        total_tokens,
+        distinct_defining_files: defining_files.len(),
+        average_occurrences_per_token,
+        tokens_per_language,
+        tokens_per_likelihood,
+    }
+}
+
+fn build_token_search_config(cmd: &SearchOpts) -> TokenSearchConfig {
+    let mut search_config = TokenSearchConfig::default();
+    search_config.progress_reporter = cmd.progress.to_reporter();
+
     if !cmd.only_filetypes.is_empty() {
         search_config.language_restriction =
             LanguageRestriction::Only(to_hash_set(&cmd.only_filetypes));
@@ -159,10 +353,19 @@ fn build_token_search_config(cmd: &Flags) -> TokenSearchConfig {
             LanguageRestriction::Except(to_hash_set(&cmd.except_filetypes));
     }
 
+    search_config.tokenizer.segment_cjk = cmd.segment_cjk;
+    search_config.tokenizer.split_compound_identifiers = cmd.split_compound_identifiers;
+
+    if let Some(path) = &cmd.cjk_dictionary {
+        if let Ok(contents) = read_file(path) {
+            search_config.tokenizer.cjk_dictionary = Dictionary::load(&contents);
+        }
+    }
+
     search_config
 }
 
-fn build_analysis_filter(cmd: &Flags) -> AnalysisFilter {
+fn build_analysis_filter(cmd: &ListOpts) -> AnalysisFilter {
     let mut analysis_filter = AnalysisFilter::default();
 
     if !cmd.likelihoods.is_empty() {
@@ -193,14 +396,14 @@ fn colorize_total(amount: usize) -> colored::ColoredString {
     }
 }
 
-fn calculate_config_by_results(_results: &TokenSearchResults) -> Option<ProjectConfiguration> {
+fn calculate_config_by_results(results: &TokenSearchResults) -> Option<ProjectConfiguration> {
     let config_path: Option<String> = dirs::home_dir().and_then(|ref p| {
         let final_path = Path::new(p).join(".unused.yml");
         final_path.to_str().map(|v| v.to_owned())
     });
     match config_path {
         Some(path) => match read_file(&path) {
-            Ok(contents) => ProjectConfigurations::load(&contents).get("Rails"),
+            Ok(contents) => ProjectConfigurations::load(&contents).best_match(results),
             _ => None,
         },
         None => None,